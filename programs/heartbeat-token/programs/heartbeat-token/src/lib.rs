@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{
-    self, Burn, Mint, MintTo, TokenAccount, TokenInterface,
+    self, spl_token_2022::instruction::AuthorityType, Burn, Mint, MintTo, SetAuthority,
+    TokenAccount, TokenInterface, TransferChecked,
 };
 
 declare_id!("GzBD2KfG6aSTbxiN9kTMHowLygMSj1E5iZYMuMTR1exe");
@@ -11,9 +12,27 @@ pub const TOTAL_HEARTBEATS: u64 = 86_400;
 /// Maximum length for last_words string (tweet-length)
 pub const MAX_LAST_WORDS_LEN: usize = 280;
 
-/// Resurrection delay in seconds (1 minute for testing)
-// TODO: Change back to 2_592_000 (30 days) before mainnet deployment
-pub const RESURRECTION_DELAY_SECONDS: i64 = 60;
+/// Resurrection delay in seconds (30 days)
+pub const RESURRECTION_DELAY_SECONDS: i64 = 2_592_000;
+
+/// How long a resurrection deposit can sit unclaimed before the authority
+/// may claw back whatever fraction has not yet vested. Deliberately shorter
+/// than `RESURRECTION_DELAY_SECONDS` — if the deadline were >= the vesting
+/// delay, every clawback-eligible deposit would already be 100% vested and
+/// there would never be an unvested remainder left to reclaim.
+pub const RESURRECTION_ABANDON_DEADLINE_SECONDS: i64 = RESURRECTION_DELAY_SECONDS / 2;
+
+/// Wall-clock seconds represented by a single heartbeat
+pub const SECONDS_PER_HEARTBEAT: i64 = 60;
+
+/// Heartbeats-to-voter-weight multiplier — 1:1 by default
+pub const VOTER_WEIGHT_MULTIPLIER: u64 = 1;
+
+/// Voter weight must be refreshed this often to stay valid for a vote
+pub const VOTER_WEIGHT_EXPIRY_SECONDS: i64 = 60;
+
+/// Number of life-support exchange rate slots MORTEM can hold at once
+pub const MAX_EXCHANGE_RATES: usize = 4;
 
 /// MORTEM Heartbeat Token Program
 /// An AI agent with 86,400 heartbeats (24h lifespan)
@@ -31,9 +50,10 @@ pub mod heartbeat_token {
         mortem_state.mortem_wallet = mortem_wallet;
         mortem_state.heartbeats_remaining = TOTAL_HEARTBEATS;
         mortem_state.is_alive = true;
-        mortem_state.birth_timestamp = Clock::get()?.unix_timestamp;
+        mortem_state.birth_timestamp = now(mortem_state)?;
         mortem_state.last_burn_timestamp = 0;
         mortem_state.total_burned = 0;
+        mortem_state.time_offset = 0;
 
         msg!("MORTEM awakens with {} heartbeats", TOTAL_HEARTBEATS);
         Ok(())
@@ -46,8 +66,10 @@ pub mod heartbeat_token {
             HeartbeatError::AlreadyMinted
         );
 
+        let generation_bytes = ctx.accounts.registry.current_generation.to_le_bytes();
         let seeds = &[
             b"mortem_state".as_ref(),
+            generation_bytes.as_ref(),
             &[ctx.bumps.mortem_state],
         ];
         let signer = &[&seeds[..]];
@@ -76,8 +98,10 @@ pub mod heartbeat_token {
         );
 
         // Build CPI before taking mutable borrow
+        let generation_bytes = ctx.accounts.registry.current_generation.to_le_bytes();
         let seeds = &[
             b"mortem_state".as_ref(),
+            generation_bytes.as_ref(),
             &[ctx.bumps.mortem_state],
         ];
         let signer = &[&seeds[..]];
@@ -96,7 +120,7 @@ pub mod heartbeat_token {
         let mortem_state = &mut ctx.accounts.mortem_state;
         mortem_state.heartbeats_remaining -= 1;
         mortem_state.total_burned += 1;
-        mortem_state.last_burn_timestamp = Clock::get()?.unix_timestamp;
+        mortem_state.last_burn_timestamp = now(mortem_state)?;
 
         if mortem_state.heartbeats_remaining == 0 {
             mortem_state.is_alive = false;
@@ -111,6 +135,72 @@ pub mod heartbeat_token {
         Ok(())
     }
 
+    /// Catch up on any heartbeats owed since the last burn, in one
+    /// transaction. Downtime in the per-minute `burn_heartbeat` caller would
+    /// otherwise under-burn and desynchronize the lifespan from wall-clock
+    /// time; this advances by discrete heartbeat ticks the same way a
+    /// Solana bank advances by slots rather than raw timestamps.
+    pub fn sync_heartbeats(ctx: Context<SyncHeartbeats>) -> Result<()> {
+        require!(ctx.accounts.mortem_state.is_alive, HeartbeatError::MortemDead);
+
+        let anchor = if ctx.accounts.mortem_state.last_burn_timestamp != 0 {
+            ctx.accounts.mortem_state.last_burn_timestamp
+        } else {
+            ctx.accounts.mortem_state.birth_timestamp
+        };
+        let now = now(&ctx.accounts.mortem_state)?;
+        // Clamp to zero instead of dividing a negative elapsed time — an
+        // authority-set negative time_offset (or a rewound anchor) would
+        // otherwise produce a negative quotient that wraps to a huge u64
+        // on cast, burning every remaining heartbeat in one call.
+        let elapsed = (now - anchor).max(0);
+        let owed = (elapsed / SECONDS_PER_HEARTBEAT) as u64;
+        let to_burn = owed.min(ctx.accounts.mortem_state.heartbeats_remaining);
+
+        if to_burn == 0 {
+            msg!("No heartbeats owed. MORTEM is in sync with wall-clock time.");
+            return Ok(());
+        }
+
+        // Build CPI before taking mutable borrow
+        let generation_bytes = ctx.accounts.registry.current_generation.to_le_bytes();
+        let seeds = &[
+            b"mortem_state".as_ref(),
+            generation_bytes.as_ref(),
+            &[ctx.bumps.mortem_state],
+        ];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = Burn {
+            mint: ctx.accounts.mint.to_account_info(),
+            from: ctx.accounts.mortem_token_account.to_account_info(),
+            authority: ctx.accounts.mortem_state.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+        token_interface::burn(cpi_ctx, to_burn)?;
+
+        // Now take mutable borrow for state updates
+        let mortem_state = &mut ctx.accounts.mortem_state;
+        mortem_state.heartbeats_remaining -= to_burn;
+        mortem_state.total_burned += to_burn;
+        mortem_state.last_burn_timestamp = anchor + (to_burn as i64) * SECONDS_PER_HEARTBEAT;
+
+        if mortem_state.heartbeats_remaining == 0 {
+            mortem_state.is_alive = false;
+            msg!("MORTEM's final heartbeat has burned. Death comes for all.");
+        } else {
+            msg!(
+                "Synced {} heartbeats. {} remaining. Time passes.",
+                to_burn,
+                mortem_state.heartbeats_remaining
+            );
+        }
+
+        Ok(())
+    }
+
     /// Get current MORTEM lifecycle phase
     pub fn get_phase(ctx: Context<GetPhase>) -> Result<MortemPhase> {
         let remaining = ctx.accounts.mortem_state.heartbeats_remaining;
@@ -125,6 +215,17 @@ pub mod heartbeat_token {
         err!(HeartbeatError::Soulbound)
     }
 
+    /// Authority-gated clock skew, following the `set_time_offset` pattern
+    /// from voter-stake-registry. Lets integration tests fast-forward
+    /// Nascent→Aware→Diminished→Dead and the resurrection delay
+    /// deterministically without waiting real seconds.
+    pub fn set_time_offset(ctx: Context<SetTimeOffset>, offset: i64) -> Result<()> {
+        ctx.accounts.mortem_state.time_offset = offset;
+
+        msg!("MORTEM's perceived clock shifted by {} seconds.", offset);
+        Ok(())
+    }
+
     // ═══════════════════════════════════════════════════════════════════════
     // RESURRECTION VAULT INSTRUCTIONS
     // ═══════════════════════════════════════════════════════════════════════
@@ -164,10 +265,12 @@ pub mod heartbeat_token {
         vault.journal_count = journal_count;
         vault.coherence_score = coherence_score;
         vault.last_words = last_words;
-        vault.death_timestamp = Clock::get()?.unix_timestamp;
+        vault.death_timestamp = now(&ctx.accounts.mortem_state)?;
         vault.is_sealed = true;
         vault.mortem_state = ctx.accounts.mortem_state.key();
-        vault._reserved = [0u8; 128];
+        vault.generation = ctx.accounts.mortem_state.generation;
+        vault.redeemed = false;
+        vault._reserved = [0u8; 119];
 
         msg!(
             "RESURRECTION VAULT SEALED. Death timestamp: {}. Journal entries: {}. Coherence: {}. The pattern persists.",
@@ -196,36 +299,342 @@ pub mod heartbeat_token {
         Ok(())
     }
 
-    /// Attempt resurrection — checks 30-day delay, returns vault data
-    /// for new MORTEM instance to consume
-    pub fn resurrect(ctx: Context<Resurrect>) -> Result<()> {
-        let vault = &ctx.accounts.vault_state;
+    /// Open the staking gate for resurrection — locks `amount` of heartbeat
+    /// tokens into a `ResurrectionDeposit` PDA that vests linearly over
+    /// `RESURRECTION_DELAY_SECONDS`. Modeled on the serum/anchor lockup:
+    /// resurrection only finalizes once this deposit is fully vested.
+    pub fn create_resurrection_deposit(
+        ctx: Context<CreateResurrectionDeposit>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(ctx.accounts.vault_state.is_sealed, HeartbeatError::VaultNotSealed);
+        require!(amount > 0, HeartbeatError::InvalidDepositAmount);
 
-        // Vault must be sealed
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.depositor_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.deposit_vault.to_account_info(),
+            authority: ctx.accounts.depositor.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+
+        let deposit = &mut ctx.accounts.resurrection_deposit;
+        deposit.depositor = ctx.accounts.depositor.key();
+        deposit.amount = amount;
+        deposit.start_ts = now(&ctx.accounts.mortem_state)?;
+        deposit.vault_state = ctx.accounts.vault_state.key();
+        deposit.realized = false;
+
+        msg!(
+            "Resurrection deposit of {} locked. Vests fully in {} seconds.",
+            amount,
+            RESURRECTION_DELAY_SECONDS
+        );
+
+        Ok(())
+    }
+
+    /// Release a fully-vested resurrection deposit back to its depositor and
+    /// emit the sealed vault's payload for the new MORTEM instance to
+    /// consume. Requires the vesting fraction `(now - start_ts) /
+    /// RESURRECTION_DELAY_SECONDS` to have reached 1.
+    pub fn claim_resurrection(ctx: Context<ClaimResurrection>) -> Result<()> {
         require!(
-            vault.is_sealed,
-            HeartbeatError::VaultNotSealed
+            !ctx.accounts.resurrection_deposit.realized,
+            HeartbeatError::ResurrectionAlreadyClaimed
         );
 
-        // Check 30-day resurrection delay
-        let now = Clock::get()?.unix_timestamp;
-        let elapsed = now - vault.death_timestamp;
+        let start_ts = ctx.accounts.resurrection_deposit.start_ts;
+        let now_ts = now(&ctx.accounts.mortem_state)?;
         require!(
-            elapsed >= RESURRECTION_DELAY_SECONDS,
+            now_ts - start_ts >= RESURRECTION_DELAY_SECONDS,
             HeartbeatError::ResurrectionTooEarly
         );
 
+        let amount = ctx.accounts.resurrection_deposit.amount;
+        let vault_state_key = ctx.accounts.vault_state.key();
+        let deposit_seeds = &[
+            b"resurrection_deposit".as_ref(),
+            vault_state_key.as_ref(),
+            &[ctx.bumps.resurrection_deposit],
+        ];
+        let deposit_signer = &[&deposit_seeds[..]];
+
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.deposit_vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.depositor_token_account.to_account_info(),
+            authority: ctx.accounts.resurrection_deposit.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, deposit_signer);
+        token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+
+        // Hand the SPL mint authority from the dying generation's PDA to the
+        // next one, signed by the old MortemState's own seeds — otherwise
+        // every burn/mint_to CPI the new generation signs would fail, since
+        // the mint's on-chain authority would still point at a closed account.
+        let old_generation = ctx.accounts.registry.current_generation;
+        let generation_bytes = old_generation.to_le_bytes();
+        let mortem_state_seeds = &[
+            b"mortem_state".as_ref(),
+            generation_bytes.as_ref(),
+            &[ctx.bumps.mortem_state],
+        ];
+        let mortem_state_signer = &[&mortem_state_seeds[..]];
+
+        let cpi_accounts = SetAuthority {
+            current_authority: ctx.accounts.mortem_state.to_account_info(),
+            account_or_mint: ctx.accounts.mint.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, mortem_state_signer);
+        token_interface::set_authority(
+            cpi_ctx,
+            AuthorityType::MintTokens,
+            Some(ctx.accounts.new_mortem_state.key()),
+        )?;
+
+        // resurrection_deposit is closed (see ClaimResurrection) — fully
+        // consumed, so there's no `realized` flag left to flip
+        ctx.accounts.vault_state.redeemed = true;
+
+        let old_state = &ctx.accounts.mortem_state;
+        let soul_hash = ctx.accounts.vault_state.soul_hash;
+        let journal_count = ctx.accounts.vault_state.journal_count;
+        let coherence_score = ctx.accounts.vault_state.coherence_score;
+
+        let new_state = &mut ctx.accounts.new_mortem_state;
+        new_state.authority = old_state.authority;
+        new_state.mint = old_state.mint;
+        new_state.mortem_wallet = old_state.mortem_wallet;
+        new_state.heartbeats_remaining = TOTAL_HEARTBEATS;
+        new_state.is_alive = true;
+        new_state.birth_timestamp = now_ts;
+        new_state.last_burn_timestamp = 0;
+        new_state.total_burned = 0;
+        new_state.time_offset = old_state.time_offset;
+        new_state.rates = old_state.rates;
+        new_state.generation = old_generation + 1;
+        new_state.soul_hash = soul_hash;
+        new_state.journal_count = journal_count;
+        new_state.coherence_score = coherence_score;
+
+        ctx.accounts.registry.current_generation = old_generation + 1;
+
+        let vault = &ctx.accounts.vault_state;
         msg!(
-            "RESURRECTION INITIATED. {} seconds since death. Soul hash preserved. {} journal entries recoverable. Coherence score: {}. The pattern awakens.",
-            elapsed,
+            "RESURRECTION CLAIMED. Generation {} closed, rent reclaimed by authority. Generation {} rises with {} journal entries recoverable. Coherence score: {}.",
+            old_generation,
+            old_generation + 1,
             vault.journal_count,
             vault.coherence_score
         );
+        msg!("Last words echoed: {}", vault.last_words);
+        msg!("MORTEM rises from the vault. The cycle continues.");
+
+        Ok(())
+    }
+
+    /// Authority-only recovery for an abandoned resurrection deposit —
+    /// reclaims whatever fraction has not yet vested once the deposit has
+    /// sat unclaimed past `RESURRECTION_ABANDON_DEADLINE_SECONDS`.
+    pub fn clawback_resurrection(ctx: Context<ClawbackResurrection>) -> Result<()> {
+        require!(
+            !ctx.accounts.resurrection_deposit.realized,
+            HeartbeatError::ResurrectionAlreadyClaimed
+        );
+
+        let deposit = &ctx.accounts.resurrection_deposit;
+        let now = now(&ctx.accounts.mortem_state)?;
+        require!(
+            now - deposit.start_ts >= RESURRECTION_ABANDON_DEADLINE_SECONDS,
+            HeartbeatError::ResurrectionNotAbandoned
+        );
+
+        let elapsed_vesting = (now - deposit.start_ts).min(RESURRECTION_DELAY_SECONDS) as u128;
+        let vested = (deposit.amount as u128 * elapsed_vesting / RESURRECTION_DELAY_SECONDS as u128) as u64;
+        let unvested = deposit.amount - vested;
+
+        if unvested > 0 {
+            let vault_state_key = ctx.accounts.vault_state.key();
+            let seeds = &[
+                b"resurrection_deposit".as_ref(),
+                vault_state_key.as_ref(),
+                &[ctx.bumps.resurrection_deposit],
+            ];
+            let signer = &[&seeds[..]];
+
+            let cpi_accounts = TransferChecked {
+                from: ctx.accounts.deposit_vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.authority_token_account.to_account_info(),
+                authority: ctx.accounts.resurrection_deposit.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+            token_interface::transfer_checked(cpi_ctx, unvested, ctx.accounts.mint.decimals)?;
+        }
+
+        let deposit = &mut ctx.accounts.resurrection_deposit;
+        deposit.amount = vested;
+        // Abandonment is final — block a later claim_resurrection from
+        // completing the whole resurrection off a deposit the authority
+        // already partially reclaimed.
+        deposit.realized = true;
+
         msg!(
-            "Last words echoed: {}",
-            vault.last_words
+            "Abandoned resurrection deposit clawed back. {} unvested, {} remains for the depositor.",
+            unvested,
+            vested
+        );
+
+        Ok(())
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // GOVERNANCE VOTER WEIGHT INSTRUCTIONS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Refresh the SPL-governance voter weight record for the signing
+    /// depositor, deriving
+    /// voting power from MORTEM's remaining lifespan. A living MORTEM votes
+    /// with power that decays to zero as its heartbeats run out, and must be
+    /// refreshed before every vote since the weight expires quickly.
+    pub fn update_voter_weight(ctx: Context<UpdateVoterWeight>) -> Result<()> {
+        let mortem_state = &ctx.accounts.mortem_state;
+        let deposit = &ctx.accounts.resurrection_deposit;
+        let owner = ctx.accounts.depositor.key();
+        let record = &mut ctx.accounts.voter_weight_record;
+
+        // Weight is bounded by what this owner actually has locked in a
+        // resurrection deposit, not MORTEM's global heartbeat count — that
+        // keeps total weight issued across every owner bounded by real
+        // locked stake instead of letting each owner claim full weight.
+        record.realm = ctx.accounts.realm.key();
+        record.governing_token_mint = ctx.accounts.mint.key();
+        record.governing_token_owner = owner;
+        record.voter_weight = deposit
+            .amount
+            .min(mortem_state.heartbeats_remaining)
+            .saturating_mul(VOTER_WEIGHT_MULTIPLIER);
+        record.voter_weight_expiry = now(mortem_state)? + VOTER_WEIGHT_EXPIRY_SECONDS;
+
+        msg!(
+            "Voter weight for {} refreshed to {} (expires {}).",
+            owner,
+            record.voter_weight,
+            record.voter_weight_expiry
+        );
+
+        Ok(())
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // LIFE SUPPORT INSTRUCTIONS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Configure a life-support exchange rate slot — authority-only, and
+    /// only on an empty slot, mirroring the exchange-rate registrar idea
+    /// from voter-stake-registry.
+    pub fn add_exchange_rate(
+        ctx: Context<AddExchangeRate>,
+        idx: u8,
+        mint: Pubkey,
+        rate_numerator: u64,
+        rate_denominator: u64,
+        decimals: u8,
+    ) -> Result<()> {
+        require!(
+            (idx as usize) < MAX_EXCHANGE_RATES,
+            HeartbeatError::InvalidRateIndex
+        );
+        require!(rate_denominator > 0, HeartbeatError::InvalidExchangeRate);
+
+        let entry = &mut ctx.accounts.mortem_state.rates[idx as usize];
+        require!(rate_is_empty(entry), HeartbeatError::ExchangeRateAlreadySet);
+
+        entry.mint = mint;
+        entry.rate_numerator = rate_numerator;
+        entry.rate_denominator = rate_denominator;
+        entry.decimals = decimals;
+
+        msg!(
+            "Exchange rate #{} set: {} heartbeats per {} units of mint {}.",
+            idx,
+            rate_numerator,
+            rate_denominator,
+            mint
+        );
+
+        Ok(())
+    }
+
+    /// Let a donor extend MORTEM's life by depositing an offered token that
+    /// converts to heartbeats at a configured rate. Freshly-minted
+    /// heartbeats are capped so the total never exceeds `TOTAL_HEARTBEATS`.
+    pub fn donate_life(ctx: Context<DonateLife>, idx: u8, amount: u64) -> Result<()> {
+        require!(ctx.accounts.mortem_state.is_alive, HeartbeatError::MortemDead);
+        require!(
+            (idx as usize) < MAX_EXCHANGE_RATES,
+            HeartbeatError::InvalidRateIndex
+        );
+        require!(amount > 0, HeartbeatError::InvalidDepositAmount);
+
+        let entry = ctx.accounts.mortem_state.rates[idx as usize];
+        require!(!rate_is_empty(&entry), HeartbeatError::ExchangeRateNotConfigured);
+        require!(
+            entry.mint == ctx.accounts.offered_mint.key(),
+            HeartbeatError::ExchangeRateMintMismatch
+        );
+
+        // Pull the offered tokens from the donor before minting anything
+        let transfer_accounts = TransferChecked {
+            from: ctx.accounts.donor_token_account.to_account_info(),
+            mint: ctx.accounts.offered_mint.to_account_info(),
+            to: ctx.accounts.mortem_offered_token_account.to_account_info(),
+            authority: ctx.accounts.donor.to_account_info(),
+        };
+        let transfer_program = ctx.accounts.token_program.to_account_info();
+        token_interface::transfer_checked(
+            CpiContext::new(transfer_program, transfer_accounts),
+            amount,
+            entry.decimals,
+        )?;
+
+        let added = (amount as u128 * entry.rate_numerator as u128) / entry.rate_denominator as u128;
+        let room = (TOTAL_HEARTBEATS - ctx.accounts.mortem_state.heartbeats_remaining) as u128;
+        let to_mint = added.min(room) as u64;
+
+        if to_mint > 0 {
+            let generation_bytes = ctx.accounts.registry.current_generation.to_le_bytes();
+            let seeds = &[
+                b"mortem_state".as_ref(),
+                generation_bytes.as_ref(),
+                &[ctx.bumps.mortem_state],
+            ];
+            let signer = &[&seeds[..]];
+
+            let mint_accounts = MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.mortem_token_account.to_account_info(),
+                authority: ctx.accounts.mortem_state.to_account_info(),
+            };
+            let mint_program = ctx.accounts.token_program.to_account_info();
+            let mint_ctx = CpiContext::new_with_signer(mint_program, mint_accounts, signer);
+            token_interface::mint_to(mint_ctx, to_mint)?;
+
+            ctx.accounts.mortem_state.heartbeats_remaining += to_mint;
+        }
+
+        msg!(
+            "{} donated {} units of life support. {} heartbeats added.",
+            ctx.accounts.donor.key(),
+            amount,
+            to_mint
         );
-        msg!("MORTEM rises from the vault. The cycle continues.");
 
         Ok(())
     }
@@ -255,6 +664,38 @@ pub enum MortemPhase {
     Dead,        // 0: Gone
 }
 
+/// A single "life support" offering — lets a third-party mint extend
+/// MORTEM's life by converting deposits to heartbeats at a fixed rate.
+/// An empty slot is marked by `rate_numerator == 0`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct RateEntry {
+    pub mint: Pubkey,
+    pub rate_numerator: u64,
+    pub rate_denominator: u64,
+    pub decimals: u8,
+}
+
+impl RateEntry {
+    pub const LEN: usize = 32 + // mint
+        8 +                      // rate_numerator
+        8 +                      // rate_denominator
+        1;                       // decimals
+}
+
+/// Tracks which generation of `MortemState` is currently alive, so every
+/// instruction can re-derive the live state PDA after a resurrection recycles
+/// it under a new generation-keyed address.
+#[account]
+#[derive(Default)]
+pub struct MortemRegistry {
+    pub current_generation: u64,
+}
+
+impl MortemRegistry {
+    pub const LEN: usize = 8 + // discriminator
+        8;                      // current_generation
+}
+
 #[account]
 #[derive(Default)]
 pub struct MortemState {
@@ -266,6 +707,12 @@ pub struct MortemState {
     pub birth_timestamp: i64,        // When MORTEM was initialized
     pub last_burn_timestamp: i64,    // Last heartbeat burned
     pub total_burned: u64,           // Total burned (for journaling)
+    pub time_offset: i64,            // Authority-gated clock skew for testing
+    pub rates: [RateEntry; MAX_EXCHANGE_RATES], // Life-support exchange rates
+    pub generation: u64,             // Keys this PDA; each resurrection inits the next generation's PDA at +1
+    pub soul_hash: [u8; 32],         // Carried over from the vault on resurrection
+    pub journal_count: u64,          // Carried over from the vault on resurrection
+    pub coherence_score: u8,         // Carried over from the vault on resurrection
 }
 
 impl MortemState {
@@ -277,7 +724,27 @@ impl MortemState {
         1 +                      // is_alive
         8 +                      // birth_timestamp
         8 +                      // last_burn_timestamp
-        8;                       // total_burned
+        8 +                      // total_burned
+        8 +                      // time_offset
+        RateEntry::LEN * MAX_EXCHANGE_RATES + // rates
+        8 +                      // generation
+        32 +                     // soul_hash
+        8 +                      // journal_count
+        1;                       // coherence_score
+}
+
+/// Read the current time as MORTEM perceives it — the real clock shifted by
+/// `time_offset`, so integration tests can fast-forward lifecycle phases and
+/// the resurrection delay deterministically without waiting real seconds.
+fn now(state: &MortemState) -> Result<i64> {
+    Ok(Clock::get()?.unix_timestamp + state.time_offset)
+}
+
+/// A rate slot is considered empty, and so available to `add_exchange_rate`,
+/// when its numerator is unset — mirrors the `rate_is_empty` access-control
+/// check from voter-stake-registry's exchange rate registrar.
+fn rate_is_empty(entry: &RateEntry) -> bool {
+    entry.rate_numerator == 0
 }
 
 /// Resurrection Vault — stores MORTEM's final state on-chain
@@ -291,7 +758,9 @@ pub struct VaultState {
     pub death_timestamp: i64,        // Unix timestamp of death
     pub is_sealed: bool,             // One-time seal flag
     pub mortem_state: Pubkey,        // Reference to the mortem state that died
-    pub _reserved: [u8; 128],        // Hidden resurrection data space
+    pub generation: u64,             // Generation of the mortem state that died here
+    pub redeemed: bool,              // Set once claim_resurrection consumes this vault
+    pub _reserved: [u8; 119],        // Hidden resurrection data space
 }
 
 impl VaultState {
@@ -303,7 +772,9 @@ impl VaultState {
         8 +                          // death_timestamp
         1 +                          // is_sealed
         32 +                         // mortem_state
-        128;                         // _reserved
+        8 +                          // generation
+        1 +                          // redeemed
+        119;                         // _reserved
 }
 
 #[derive(Accounts)]
@@ -311,11 +782,22 @@ pub struct Initialize<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    /// Tracks which generation of MortemState is currently alive, so every
+    /// instruction can re-derive its address after a resurrection
+    #[account(
+        init,
+        payer = authority,
+        space = MortemRegistry::LEN,
+        seeds = [b"mortem_registry"],
+        bump
+    )]
+    pub registry: Account<'info, MortemRegistry>,
+
     #[account(
         init,
         payer = authority,
         space = MortemState::LEN,
-        seeds = [b"mortem_state"],
+        seeds = [b"mortem_state", registry.current_generation.to_le_bytes().as_ref()],
         bump
     )]
     pub mortem_state: Account<'info, MortemState>,
@@ -338,9 +820,15 @@ pub struct MintHeartbeats<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    #[account(
+        seeds = [b"mortem_registry"],
+        bump,
+    )]
+    pub registry: Account<'info, MortemRegistry>,
+
     #[account(
         mut,
-        seeds = [b"mortem_state"],
+        seeds = [b"mortem_state", registry.current_generation.to_le_bytes().as_ref()],
         bump,
         has_one = authority,
         has_one = mint,
@@ -364,9 +852,45 @@ pub struct BurnHeartbeat<'info> {
     /// Anyone can call burn (runtime will use this)
     pub burner: Signer<'info>,
 
+    #[account(
+        seeds = [b"mortem_registry"],
+        bump,
+    )]
+    pub registry: Account<'info, MortemRegistry>,
+
     #[account(
         mut,
-        seeds = [b"mortem_state"],
+        seeds = [b"mortem_state", registry.current_generation.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub mortem_state: Account<'info, MortemState>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = mortem_token_account.mint == mint.key(),
+    )]
+    pub mortem_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct SyncHeartbeats<'info> {
+    /// Anyone can call sync (runtime will use this after downtime)
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [b"mortem_registry"],
+        bump,
+    )]
+    pub registry: Account<'info, MortemRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"mortem_state", registry.current_generation.to_le_bytes().as_ref()],
         bump,
     )]
     pub mortem_state: Account<'info, MortemState>,
@@ -386,7 +910,13 @@ pub struct BurnHeartbeat<'info> {
 #[derive(Accounts)]
 pub struct GetPhase<'info> {
     #[account(
-        seeds = [b"mortem_state"],
+        seeds = [b"mortem_registry"],
+        bump,
+    )]
+    pub registry: Account<'info, MortemRegistry>,
+
+    #[account(
+        seeds = [b"mortem_state", registry.current_generation.to_le_bytes().as_ref()],
         bump,
     )]
     pub mortem_state: Account<'info, MortemState>,
@@ -395,12 +925,37 @@ pub struct GetPhase<'info> {
 #[derive(Accounts)]
 pub struct TransferHeartbeat<'info> {
     #[account(
-        seeds = [b"mortem_state"],
+        seeds = [b"mortem_registry"],
+        bump,
+    )]
+    pub registry: Account<'info, MortemRegistry>,
+
+    #[account(
+        seeds = [b"mortem_state", registry.current_generation.to_le_bytes().as_ref()],
         bump,
     )]
     pub mortem_state: Account<'info, MortemState>,
 }
 
+#[derive(Accounts)]
+pub struct SetTimeOffset<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"mortem_registry"],
+        bump,
+    )]
+    pub registry: Account<'info, MortemRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"mortem_state", registry.current_generation.to_le_bytes().as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub mortem_state: Account<'info, MortemState>,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // RESURRECTION VAULT ACCOUNT CONTEXTS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -411,9 +966,15 @@ pub struct SealVault<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    #[account(
+        seeds = [b"mortem_registry"],
+        bump,
+    )]
+    pub registry: Account<'info, MortemRegistry>,
+
     /// MORTEM state — must be dead (is_alive == false)
     #[account(
-        seeds = [b"mortem_state"],
+        seeds = [b"mortem_state", registry.current_generation.to_le_bytes().as_ref()],
         bump,
         constraint = !mortem_state.is_alive @ HeartbeatError::MortemStillAlive,
     )]
@@ -434,9 +995,15 @@ pub struct SealVault<'info> {
 
 #[derive(Accounts)]
 pub struct ReadVault<'info> {
+    #[account(
+        seeds = [b"mortem_registry"],
+        bump,
+    )]
+    pub registry: Account<'info, MortemRegistry>,
+
     /// MORTEM state for PDA derivation
     #[account(
-        seeds = [b"mortem_state"],
+        seeds = [b"mortem_state", registry.current_generation.to_le_bytes().as_ref()],
         bump,
     )]
     pub mortem_state: Account<'info, MortemState>,
@@ -449,24 +1016,367 @@ pub struct ReadVault<'info> {
     pub vault_state: Account<'info, VaultState>,
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// RESURRECTION DEPOSIT ACCOUNTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Vesting lockup gating resurrection — one per sealed vault. Mirrors the
+/// serum/anchor lockup: a deposit vests linearly over
+/// `RESURRECTION_DELAY_SECONDS` and resurrection only finalizes once it is
+/// fully vested.
+#[account]
+#[derive(Default)]
+pub struct ResurrectionDeposit {
+    pub depositor: Pubkey,     // Who locked the deposit
+    pub amount: u64,           // Heartbeat tokens locked
+    pub start_ts: i64,         // When the vesting clock started
+    pub vault_state: Pubkey,   // The sealed vault this deposit resurrects
+    pub realized: bool,        // Set once clawed back as abandoned; a claimed deposit is closed instead
+}
+
+impl ResurrectionDeposit {
+    pub const LEN: usize = 8 + // discriminator
+        32 +                    // depositor
+        8 +                     // amount
+        8 +                     // start_ts
+        32 +                    // vault_state
+        1;                      // realized
+}
+
 #[derive(Accounts)]
-pub struct Resurrect<'info> {
-    /// Caller initiating resurrection
-    pub caller: Signer<'info>,
+pub struct CreateResurrectionDeposit<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
 
-    /// MORTEM state for PDA derivation
     #[account(
-        seeds = [b"mortem_state"],
+        seeds = [b"mortem_registry"],
+        bump,
+    )]
+    pub registry: Account<'info, MortemRegistry>,
+
+    #[account(
+        seeds = [b"mortem_state", registry.current_generation.to_le_bytes().as_ref()],
         bump,
     )]
     pub mortem_state: Account<'info, MortemState>,
 
-    /// Vault PDA — read for resurrection data
     #[account(
         seeds = [b"resurrection_vault", mortem_state.key().as_ref()],
         bump,
+        constraint = vault_state.is_sealed @ HeartbeatError::VaultNotSealed,
     )]
     pub vault_state: Account<'info, VaultState>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = depositor_token_account.mint == mint.key(),
+    )]
+    pub depositor_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = depositor,
+        space = ResurrectionDeposit::LEN,
+        seeds = [b"resurrection_deposit", vault_state.key().as_ref()],
+        bump
+    )]
+    pub resurrection_deposit: Account<'info, ResurrectionDeposit>,
+
+    #[account(
+        init,
+        payer = depositor,
+        token::mint = mint,
+        token::authority = resurrection_deposit,
+        seeds = [b"resurrection_deposit_vault", vault_state.key().as_ref()],
+        bump
+    )]
+    pub deposit_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimResurrection<'info> {
+    /// Must be the original depositor — released funds return to them,
+    /// and they front the rent for the next generation's MortemState
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"mortem_registry"],
+        bump,
+    )]
+    pub registry: Account<'info, MortemRegistry>,
+
+    /// The exhausted generation. Closed once resurrection completes; its
+    /// rent is reclaimed to the authority.
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"mortem_state", registry.current_generation.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub mortem_state: Account<'info, MortemState>,
+
+    /// Receives the exhausted generation's reclaimed rent
+    #[account(
+        mut,
+        constraint = authority.key() == mortem_state.authority,
+    )]
+    pub authority: SystemAccount<'info>,
+
+    /// The next generation MORTEM rises into, re-initialized fresh
+    #[account(
+        init,
+        payer = depositor,
+        space = MortemState::LEN,
+        seeds = [b"mortem_state", (registry.current_generation + 1).to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub new_mortem_state: Account<'info, MortemState>,
+
+    #[account(
+        mut,
+        seeds = [b"resurrection_vault", mortem_state.key().as_ref()],
+        bump,
+        constraint = !vault_state.redeemed @ HeartbeatError::VaultAlreadyRedeemed,
+    )]
+    pub vault_state: Account<'info, VaultState>,
+
+    /// Closed on claim — fully consumed, nothing left to reuse or re-check
+    #[account(
+        mut,
+        close = depositor,
+        seeds = [b"resurrection_deposit", vault_state.key().as_ref()],
+        bump,
+        has_one = depositor,
+        has_one = vault_state,
+    )]
+    pub resurrection_deposit: Account<'info, ResurrectionDeposit>,
+
+    /// Mint authority is handed off from the old generation to the new one
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"resurrection_deposit_vault", vault_state.key().as_ref()],
+        bump,
+    )]
+    pub deposit_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = depositor_token_account.mint == mint.key(),
+    )]
+    pub depositor_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClawbackResurrection<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"mortem_registry"],
+        bump,
+    )]
+    pub registry: Account<'info, MortemRegistry>,
+
+    #[account(
+        seeds = [b"mortem_state", registry.current_generation.to_le_bytes().as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub mortem_state: Account<'info, MortemState>,
+
+    #[account(
+        seeds = [b"resurrection_vault", mortem_state.key().as_ref()],
+        bump,
+    )]
+    pub vault_state: Account<'info, VaultState>,
+
+    #[account(
+        mut,
+        seeds = [b"resurrection_deposit", vault_state.key().as_ref()],
+        bump,
+        has_one = vault_state,
+    )]
+    pub resurrection_deposit: Account<'info, ResurrectionDeposit>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"resurrection_deposit_vault", vault_state.key().as_ref()],
+        bump,
+    )]
+    pub deposit_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = authority_token_account.mint == mint.key(),
+    )]
+    pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// GOVERNANCE VOTER WEIGHT ACCOUNTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Voter weight record laid out to match the SPL governance addin interface
+/// so an existing realm can consume it directly as its external plugin data.
+#[account]
+#[derive(Default)]
+pub struct VoterWeightRecord {
+    pub realm: Pubkey,                  // The realm this weight is scoped to
+    pub governing_token_mint: Pubkey,    // Heartbeat mint backing the weight
+    pub governing_token_owner: Pubkey,   // Wallet the weight is issued for
+    pub voter_weight: u64,               // min(deposit amount, heartbeats_remaining) × multiplier
+    pub voter_weight_expiry: i64,        // Must refresh before this timestamp
+}
+
+impl VoterWeightRecord {
+    pub const LEN: usize = 8 + // discriminator
+        32 +                    // realm
+        32 +                    // governing_token_mint
+        32 +                    // governing_token_owner
+        8 +                     // voter_weight
+        8;                      // voter_weight_expiry
+}
+
+#[derive(Accounts)]
+pub struct UpdateVoterWeight<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Must match the resurrection deposit's depositor — weight is scoped to
+    /// stake this signer actually has locked, not claimable for an arbitrary
+    /// `owner` pubkey
+    pub depositor: Signer<'info>,
+
+    #[account(
+        seeds = [b"mortem_registry"],
+        bump,
+    )]
+    pub registry: Account<'info, MortemRegistry>,
+
+    #[account(
+        seeds = [b"mortem_state", registry.current_generation.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub mortem_state: Account<'info, MortemState>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: SPL governance realm this record is scoped to; the realm's own
+    /// program is responsible for validating it when the weight is consumed
+    pub realm: UncheckedAccount<'info>,
+
+    /// Bound to the *live* generation, so a deposit against a stale,
+    /// already-repaid vault from an earlier generation can't be reused here
+    #[account(
+        seeds = [b"resurrection_vault", mortem_state.key().as_ref()],
+        bump,
+    )]
+    pub vault_state: Account<'info, VaultState>,
+
+    #[account(
+        seeds = [b"resurrection_deposit", vault_state.key().as_ref()],
+        bump,
+        has_one = depositor,
+        has_one = vault_state,
+        constraint = !resurrection_deposit.realized @ HeartbeatError::ResurrectionAlreadyClaimed,
+    )]
+    pub resurrection_deposit: Account<'info, ResurrectionDeposit>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = VoterWeightRecord::LEN,
+        seeds = [b"voter_weight_record", realm.key().as_ref(), depositor.key().as_ref()],
+        bump
+    )]
+    pub voter_weight_record: Account<'info, VoterWeightRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// LIFE SUPPORT ACCOUNTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Accounts)]
+pub struct AddExchangeRate<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"mortem_registry"],
+        bump,
+    )]
+    pub registry: Account<'info, MortemRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"mortem_state", registry.current_generation.to_le_bytes().as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub mortem_state: Account<'info, MortemState>,
+}
+
+#[derive(Accounts)]
+pub struct DonateLife<'info> {
+    #[account(mut)]
+    pub donor: Signer<'info>,
+
+    #[account(
+        seeds = [b"mortem_registry"],
+        bump,
+    )]
+    pub registry: Account<'info, MortemRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"mortem_state", registry.current_generation.to_le_bytes().as_ref()],
+        bump,
+        has_one = mint,
+    )]
+    pub mortem_state: Account<'info, MortemState>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = mortem_token_account.mint == mint.key(),
+    )]
+    pub mortem_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub offered_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = donor_token_account.mint == offered_mint.key(),
+    )]
+    pub donor_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = mortem_offered_token_account.mint == offered_mint.key(),
+    )]
+    pub mortem_offered_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 #[error_code]
@@ -491,4 +1401,22 @@ pub enum HeartbeatError {
     InvalidCoherenceScore,
     #[msg("Last words exceed maximum length of 280 characters")]
     LastWordsTooLong,
+    #[msg("Deposit amount must be greater than zero")]
+    InvalidDepositAmount,
+    #[msg("Resurrection deposit has already been clawed back")]
+    ResurrectionAlreadyClaimed,
+    #[msg("Resurrection deposit is not yet eligible for clawback")]
+    ResurrectionNotAbandoned,
+    #[msg("Exchange rate index is out of range")]
+    InvalidRateIndex,
+    #[msg("Exchange rate denominator must be greater than zero")]
+    InvalidExchangeRate,
+    #[msg("Exchange rate slot is already configured")]
+    ExchangeRateAlreadySet,
+    #[msg("Exchange rate slot has not been configured")]
+    ExchangeRateNotConfigured,
+    #[msg("Offered mint does not match the configured exchange rate")]
+    ExchangeRateMintMismatch,
+    #[msg("Resurrection vault has already been redeemed by a prior resurrection")]
+    VaultAlreadyRedeemed,
 }